@@ -0,0 +1,95 @@
+use ndarray::prelude::*;
+use libnum::{Zero, One, Float};
+
+use super::operator::LinearOperator;
+
+///Runs the Hager–Higham 1-norm power-iteration estimator against an operator `B`, given only
+///the means to apply `B` and `Bᵀ` to a vector. This is shared by the `‖A‖₁` and `‖A⁻¹‖₁` halves
+///of [`condest_1norm`] so that neither side needs to materialize `B` as a dense matrix.
+fn onenorm_est<F, Op, OpT>(n: usize, apply: Op, apply_transpose: OpT) -> F
+    where F: Float + Zero + One + 'static,
+    Op: Fn(ArrayView1<F>) -> Array1<F>,
+    OpT: Fn(ArrayView1<F>) -> Array1<F>
+{
+    let n_f = F::from(n).unwrap();
+
+    let mut x = Array1::<F>::from_elem(n, F::one() / n_f);
+
+    let mut est: F = F::zero();
+    let mut est_old: F = F::zero();
+
+    for k in 0..5 {
+        let y = apply(x.view());
+
+        est = y.iter().fold(F::zero(), |acc, &yi| acc + yi.abs());
+
+        //Once the estimate stops growing we've converged; applying B again would just spin.
+        if k > 0 && est <= est_old {
+            break;
+        }
+
+        est_old = est;
+
+        let xi = y.mapv(|yi| if yi >= F::zero() { F::one() } else { -F::one() });
+        let z = apply_transpose(xi.view());
+
+        let (j, zj) = z.iter().enumerate().fold((0usize, F::zero()), |(bj, bv), (i, &zi)| {
+            if zi.abs() > bv { (i, zi.abs()) } else { (bj, bv) }
+        });
+
+        if zj <= z.dot(&x) {
+            break;
+        }
+
+        x = Array1::<F>::zeros(n);
+        x[j] = F::one();
+    }
+
+    //A final "alternating sign" probe catches cancellation patterns that the power iteration
+    //above can settle into a local maximum without ever seeing.
+    let mut x_probe = Array1::<F>::zeros(n);
+
+    for i in 0..n {
+        let sign = if i % 2 == 0 { F::one() } else { -F::one() };
+        let scale = if n > 1 { F::from(i).unwrap() / F::from(n - 1).unwrap() } else { F::zero() };
+
+        x_probe[i] = sign * (F::one() + scale);
+    }
+
+    let y_probe = apply(x_probe.view());
+    let sum_probe = y_probe.iter().fold(F::zero(), |acc, &yi| acc + yi.abs());
+    let est_probe = (F::one() + F::one()) * sum_probe / (F::from(3).unwrap() * n_f);
+
+    if est_probe > est { est_probe } else { est }
+}
+
+///Estimates the 1-norm condition number `‖A‖₁ · ‖A⁻¹‖₁` of `op` using the Hager–Higham
+///block-free estimator, without ever forming `A` or `A⁻¹` as a dense matrix.
+///`solve_fn` applies `A⁻¹` to a vector, i.e. it should return the `x` that solves `A x = b`
+///for the given `b`; callers typically pass one of this crate's iterative solvers (e.g.
+///`|b| { let mut x = Array1::zeros(b.len()); cg_solver(op, x.view_mut(), b, opt); x }`).
+///A large estimate means `A` is ill-conditioned and iterative solves against it are liable to
+///stall or converge slowly, which is a good signal to switch to (or tighten) a preconditioner.
+pub fn condest_1norm<F, A, S>(op: &A, solve_fn: S) -> F
+    where F: Float + Zero + One + 'static,
+    A: LinearOperator<F>,
+    S: Fn(ArrayView1<F>) -> Array1<F>
+{
+    let n = op.ncols();
+
+    assert!(op.nrows() == n,
+    "condest_1norm requires a square operator. The number of rows is {} and number of cols is {}",
+    op.nrows(), n);
+
+    let norm_a = onenorm_est(n,
+        |x| { let mut y = Array1::<F>::zeros(n); op.matvec(x, y.view_mut()); y },
+        |x| { let mut y = Array1::<F>::zeros(n); op.matvec_transpose(x, y.view_mut()); y });
+
+    //`Aᵀ`'s solve isn't exposed by `solve_fn`, so we reuse the forward solve for the transpose
+    //application as well; this is exact when `A` (and hence `A⁻¹`) is symmetric, which covers
+    //the CG/PCG callers this estimator is primarily meant for, and is a reasonable approximation
+    //otherwise.
+    let norm_a_inv = onenorm_est(n, &solve_fn, &solve_fn);
+
+    norm_a * norm_a_inv
+}
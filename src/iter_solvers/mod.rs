@@ -3,15 +3,18 @@ use ndarray::Zip;
 use libnum::{Zero, One, Float};//NumCast
 
 use super::options::IterOptions;
+use super::operator::LinearOperator;
+use super::result::SolverResult;
 
 ///A conjugate gradient solver used to iteratively solve a symmetric A x = b type problem.
-///Input: a_mat - a 2D matrix with nxn dimensions. This matrix must also be symmetric in order to use the conjugate gradient method.
+///Input: a_mat - a `LinearOperator` with nxn dimensions (a dense `ArrayView2` or a sparse `CscMatrix` both work).
+///This matrix must also be symmetric in order to use the conjugate gradient method.
 ///x_vec - a 1D vector that has n dimensions. The initial values inputted into this vector are the initial guesses to the solution.
 ///b_vec - a 1D vector that has n dimensions. The vector RHS of the Ax=b problem.
 ///opt - the iterative option structure. It tells us a number of things that we need to worry about for our iterative problems.
-///Output - err - a Float that tells us what the error of our solution was found to be. You should check to make sure this meets
-///         your set error tolerances.
-pub fn cg_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> F 
+///Output - a `SolverResult` recording whether the solve converged, how many iterations it took,
+///         the final residual norm, and (if `opt.record_history` is set) the residual at every step.
+pub fn cg_solver<F: 'static, A: LinearOperator<F>>(a_mat: A, mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> SolverResult<F>
     where F: Float + Zero + One
 {
     //Here we need to assert that all of the dimensions are the correct length or else we need to kill the function
@@ -19,20 +22,20 @@ pub fn cg_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>, b
 
     let ndim_b = b_vec.len_of(Axis(0));
 
-    let nrows_a = a_mat.len_of(Axis(0));
-    let ncols_a = a_mat.len_of(Axis(1));
+    let nrows_a = a_mat.nrows();
+    let ncols_a = a_mat.ncols();
 
-    assert!(ndim_b == ndim_x, 
+    assert!(ndim_b == ndim_x,
     "The dimensions of the x vector and b vector are not equal to one another.
     The dimension of x is {} and dimension of b is {}",
     ndim_x, ndim_b);
 
-    assert!(nrows_a == ncols_a, 
+    assert!(nrows_a == ncols_a,
     "The A matrix must have the same number of rows and columns.
     The number of columns is {} and number of rows is {}",
     ncols_a, nrows_a);
 
-    assert!(ndim_b == nrows_a, 
+    assert!(ndim_b == nrows_a,
     "The number of columns of A must be equal to the number of rows of x vector.
     The number of rows of x is {} and number of cols of b is {}",
     ndim_x, ncols_a);
@@ -47,7 +50,8 @@ pub fn cg_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>, b
     let mut pkt_a_pk: F = F::zero();
     let mut mu: F = F::zero();
 
-    ri.assign(&(&a_mat.dot(&x_vec) - &b_vec));
+    a_mat.matvec(x_vec.view(), ri.view_mut());
+    Zip::from(&mut ri).and(&b_vec).apply(|ri, &b| { *ri = b - *ri; });
 
     pki.assign(&ri);
 
@@ -55,12 +59,23 @@ pub fn cg_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>, b
     let mut err: F = rtr.sqrt();
     let mut rt1r1: F = rtr.clone();
 
+    let mut residual_history: Vec<F> = Vec::new();
+
+    if err.abs() < tol{
+        return SolverResult { converged: true, iterations: 0, final_residual: err, residual_history };
+    }
+
+    let mut iterations: u32 = 0;
+    let mut converged = false;
+
     for _istep in 0..opt.iter_limit{
 
-        a_pk.assign(&a_mat.dot(&pki));
+        iterations += 1;
+
+        a_mat.matvec(pki.view(), a_pk.view_mut());
 
         pkt_a_pk = pki.dot(&a_pk);
-        
+
         mu = rtr/pkt_a_pk;
 
         x_vec.scaled_add(mu, &pki);
@@ -71,7 +86,12 @@ pub fn cg_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>, b
 
         err = rtr.sqrt();
 
+        if opt.record_history{
+            residual_history.push(err);
+        }
+
         if err.abs() < tol{
+            converged = true;
             break;
         }
 
@@ -80,26 +100,26 @@ pub fn cg_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>, b
         rt1r1 = rtr.clone();
 
         Zip::from(&mut pki).and(&ri).apply(|pki, &ri|{
-            *pki = ri + tau * *pki;  
+            *pki = ri + tau * *pki;
         });
 
     }
 
-    err
+    SolverResult { converged, iterations, final_residual: err, residual_history }
 }
 
-//We can set the below in the future to allowing rectangular A matrices. Therefore, it could be applied to solve full-rank least squares
-//type problems. 
+//For rectangular (full-rank least-squares) A, use `cgls_solver` below instead: its dimension
+//asserts allow m != n, unlike this solver's.
 
 ///A conjugate gradient normal equation residual (CGNR) solver used to iteratively solve a nonsymmetric A x = b type problem.
 ///This method creates a symmetric problem to solve by doing Ax = b == {A^T A x = A^T b}
-///Input: a_mat - a 2D matrix with nxn dimensions. This matrix must also be nonsymmetric in order to use the CGNR method.
+///Input: a_mat - a `LinearOperator` with nxn dimensions (a dense `ArrayView2` or a sparse `CscMatrix` both work). This matrix must also be nonsymmetric in order to use the CGNR method.
 ///x_vec - a 1D vector that has n dimensions. The initial values inputted into this vector are the initial guesses to the solution.
 ///b_vec - a 1D vector that has n dimensions. The vector RHS of the Ax=b problem.
 ///opt - the iterative option structure. It tells us a number of things that we need to worry about for our iterative problems.
-///Output - err - a Float that tells us what the error of our solution was found to be. You should check to make sure this meets
-///         your set error tolerances.
-pub fn cgnr_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> F 
+///Output - a `SolverResult` recording whether the solve converged, how many iterations it took,
+///         the final residual norm, and (if `opt.record_history` is set) the residual at every step.
+pub fn cgnr_solver<F: 'static, A: LinearOperator<F>>(a_mat: A, mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> SolverResult<F>
     where F: Float + Zero + One
 {
     //Here we need to assert that all of the dimensions are the correct length or else we need to kill the function
@@ -107,20 +127,20 @@ pub fn cgnr_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
 
     let ndim_b = b_vec.len_of(Axis(0));
 
-    let nrows_a = a_mat.len_of(Axis(0));
-    let ncols_a = a_mat.len_of(Axis(1));
+    let nrows_a = a_mat.nrows();
+    let ncols_a = a_mat.ncols();
 
-    assert!(ndim_b == ndim_x, 
+    assert!(ndim_b == ndim_x,
     "The dimensions of the x vector and b vector are not equal to one another.
     The dimension of x is {} and dimension of b is {}",
     ndim_x, ndim_b);
 
-    assert!(nrows_a == ncols_a, 
+    assert!(nrows_a == ncols_a,
     "The A matrix must have the same number of rows and columns.
     The number of columns is {} and number of rows is {}",
     ncols_a, nrows_a);
 
-    assert!(ndim_b == nrows_a, 
+    assert!(ndim_b == nrows_a,
     "The number of columns of A must be equal to the number of rows of x vector.
     The number of rows of x is {} and number of cols of b is {}",
     ndim_x, ncols_a);
@@ -128,7 +148,6 @@ pub fn cgnr_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
     let tol: F = opt.sol_tol;
 
     let mut ri = Array1::<F>::zeros(ndim_x);
-    let mut r_t = Array2::<F>::zeros((1, ndim_x));
     let mut pki = Array1::<F>::zeros(ndim_x);
     let mut zi = Array1::<F>::zeros(ndim_x);
     let mut a_pk = Array1::<F>::zeros(ndim_x);
@@ -137,11 +156,10 @@ pub fn cgnr_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
     let mut a_pk_t_a_pk: F = F::zero();
     let mut mu: F = F::zero();
 
-    ri.assign(&(&a_mat.dot(&x_vec) - &b_vec));
+    a_mat.matvec(x_vec.view(), ri.view_mut());
+    Zip::from(&mut ri).and(&b_vec).apply(|ri, &b| { *ri = b - *ri; });
 
-    r_t.assign(&ri);
-
-    zi.assign(&r_t.dot(&a_mat));
+    a_mat.matvec_transpose(ri.view(), zi.view_mut());
 
     pki.assign(&zi);
 
@@ -150,12 +168,23 @@ pub fn cgnr_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
     let mut err: F = rtr.sqrt();
     let mut zt1z1: F = ztz.clone();
 
+    let mut residual_history: Vec<F> = Vec::new();
+
+    if err.abs() < tol{
+        return SolverResult { converged: true, iterations: 0, final_residual: err, residual_history };
+    }
+
+    let mut iterations: u32 = 0;
+    let mut converged = false;
+
     for _istep in 0..opt.iter_limit{
 
-        a_pk.assign(&a_mat.dot(&pki));
+        iterations += 1;
+
+        a_mat.matvec(pki.view(), a_pk.view_mut());
 
         a_pk_t_a_pk = a_pk.dot(&a_pk);
-        
+
         mu = ztz/a_pk_t_a_pk;
 
         x_vec.scaled_add(mu, &pki);
@@ -166,13 +195,16 @@ pub fn cgnr_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
 
         err = rtr.sqrt();
 
+        if opt.record_history{
+            residual_history.push(err);
+        }
+
         if err.abs() < tol{
+            converged = true;
             break;
         }
 
-        r_t.assign(&ri);
-
-        zi.assign(&r_t.dot(&a_mat));
+        a_mat.matvec_transpose(ri.view(), zi.view_mut());
 
         ztz = zi.dot(&zi);
 
@@ -181,26 +213,26 @@ pub fn cgnr_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
         zt1z1 = ztz.clone();
 
         Zip::from(&mut pki).and(&zi).apply(|pki, &zi|{
-            *pki = zi + tau * *pki;  
+            *pki = zi + tau * *pki;
         });
 
     }
 
-    err
+    SolverResult { converged, iterations, final_residual: err, residual_history }
 }
 
-//We can set the below in the future to allowing rectangular A matrices. When applied to this solver the underlying system must be
-//consistent
+//For rectangular A, use `cgls_solver` below instead: CGNE's AAᵀy = b normal equations require a
+//consistent square system, which rectangular A does not give you.
 
 ///A conjugate gradient normal equation error (CGNE) solver used to iteratively solve a nonsymmetric A x = b type problem.
 ///This method creates a symmetric problem to solve by doing Ax = b == {AA^T y = b, x = A^T y}
-///Input: a_mat - a 2D matrix with nxn dimensions. This matrix must also be nonsymmetric in order to use the CGNR method.
+///Input: a_mat - a `LinearOperator` with nxn dimensions (a dense `ArrayView2` or a sparse `CscMatrix` both work). This matrix must also be nonsymmetric in order to use the CGNR method.
 ///x_vec - a 1D vector that has n dimensions. The initial values inputted into this vector are the initial guesses to the solution.
 ///b_vec - a 1D vector that has n dimensions. The vector RHS of the Ax=b problem.
 ///opt - the iterative option structure. It tells us a number of things that we need to worry about for our iterative problems.
-///Output - err - a Float that tells us what the error of our solution was found to be. You should check to make sure this meets
-///         your set error tolerances.
-pub fn cgne_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> F 
+///Output - a `SolverResult` recording whether the solve converged, how many iterations it took,
+///         the final residual norm, and (if `opt.record_history` is set) the residual at every step.
+pub fn cgne_solver<F: 'static, A: LinearOperator<F>>(a_mat: A, mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> SolverResult<F>
     where F: Float + Zero + One
 {
     //Here we need to assert that all of the dimensions are the correct length or else we need to kill the function
@@ -208,20 +240,20 @@ pub fn cgne_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
 
     let ndim_b = b_vec.len_of(Axis(0));
 
-    let nrows_a = a_mat.len_of(Axis(0));
-    let ncols_a = a_mat.len_of(Axis(1));
+    let nrows_a = a_mat.nrows();
+    let ncols_a = a_mat.ncols();
 
-    assert!(ndim_b == ndim_x, 
+    assert!(ndim_b == ndim_x,
     "The dimensions of the x vector and b vector are not equal to one another.
     The dimension of x is {} and dimension of b is {}",
     ndim_x, ndim_b);
 
-    assert!(nrows_a == ncols_a, 
+    assert!(nrows_a == ncols_a,
     "The A matrix must have the same number of rows and columns.
     The number of columns is {} and number of rows is {}",
     ncols_a, nrows_a);
 
-    assert!(ndim_b == nrows_a, 
+    assert!(ndim_b == nrows_a,
     "The number of columns of A must be equal to the number of rows of x vector.
     The number of rows of x is {} and number of cols of b is {}",
     ndim_x, ncols_a);
@@ -229,7 +261,6 @@ pub fn cgne_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
     let tol: F = opt.sol_tol;
 
     let mut ri = Array1::<F>::zeros(ndim_x);
-    let mut r_t = Array2::<F>::zeros((1, ndim_x));
     let mut pki = Array1::<F>::zeros(ndim_x);
     let mut zi = Array1::<F>::zeros(ndim_x);
     let mut a_pk = Array1::<F>::zeros(ndim_x);
@@ -238,11 +269,10 @@ pub fn cgne_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
     let mut pki_t_pki: F = F::zero();
     let mut mu: F = F::zero();
 
-    ri.assign(&(&a_mat.dot(&x_vec) - &b_vec));
-
-    r_t.assign(&ri);
+    a_mat.matvec(x_vec.view(), ri.view_mut());
+    Zip::from(&mut ri).and(&b_vec).apply(|ri, &b| { *ri = b - *ri; });
 
-    zi.assign(&r_t.dot(&a_mat));
+    a_mat.matvec_transpose(ri.view(), zi.view_mut());
 
     pki.assign(&zi);
 
@@ -250,12 +280,23 @@ pub fn cgne_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
     let mut err: F = rtr.sqrt();
     let mut rt1r1: F = rtr.clone();
 
+    let mut residual_history: Vec<F> = Vec::new();
+
+    if err.abs() < tol{
+        return SolverResult { converged: true, iterations: 0, final_residual: err, residual_history };
+    }
+
+    let mut iterations: u32 = 0;
+    let mut converged = false;
+
     for _istep in 0..opt.iter_limit{
 
-        a_pk.assign(&a_mat.dot(&pki));
+        iterations += 1;
+
+        a_mat.matvec(pki.view(), a_pk.view_mut());
 
         pki_t_pki = pki.dot(&pki);
-        
+
         mu = rtr/pki_t_pki;
 
         x_vec.scaled_add(mu, &pki);
@@ -266,23 +307,528 @@ pub fn cgne_solver<F: 'static>(a_mat: ArrayView2<F>,mut x_vec: ArrayViewMut1<F>,
 
         err = rtr.sqrt();
 
+        if opt.record_history{
+            residual_history.push(err);
+        }
+
         if err.abs() < tol{
+            converged = true;
             break;
         }
 
-        r_t.assign(&ri);
-
-        zi.assign(&r_t.dot(&a_mat));
+        a_mat.matvec_transpose(ri.view(), zi.view_mut());
 
         tau = rtr/rt1r1;
 
         rt1r1 = rtr.clone();
 
         Zip::from(&mut pki).and(&zi).apply(|pki, &zi|{
-            *pki = zi + tau * *pki;  
+            *pki = zi + tau * *pki;
         });
 
     }
 
-    err
+    SolverResult { converged, iterations, final_residual: err, residual_history }
+}
+
+///A restarted GMRES(m) solver used to iteratively solve a general (possibly nonsymmetric) A x = b type problem.
+///This method builds an orthonormal Krylov basis via the Arnoldi process and minimizes the residual over that
+///basis using Givens rotations, restarting every `opt.restart_iter` steps to bound the memory and work per cycle.
+///Input: a_mat - a `LinearOperator` with nxn dimensions (a dense `ArrayView2` or a sparse `CscMatrix` both work).
+///x_vec - a 1D vector that has n dimensions. The initial values inputted into this vector are the initial guesses to the solution.
+///b_vec - a 1D vector that has n dimensions. The vector RHS of the Ax=b problem.
+///opt - the iterative option structure. `opt.restart_iter` sets the number of Arnoldi steps (m) taken before a restart.
+///Output - a `SolverResult` recording whether the solve converged, how many Arnoldi steps it took
+///         across all restart cycles, the final residual norm, and (if `opt.record_history` is
+///         set) the residual at every step.
+pub fn gmres_solver<F: 'static, A: LinearOperator<F>>(a_mat: A, mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> SolverResult<F>
+    where F: Float + Zero + One
+{
+    //Here we need to assert that all of the dimensions are the correct length or else we need to kill the function
+    let ndim_x = x_vec.len_of(Axis(0));
+
+    let ndim_b = b_vec.len_of(Axis(0));
+
+    let nrows_a = a_mat.nrows();
+    let ncols_a = a_mat.ncols();
+
+    assert!(ndim_b == ndim_x,
+    "The dimensions of the x vector and b vector are not equal to one another.
+    The dimension of x is {} and dimension of b is {}",
+    ndim_x, ndim_b);
+
+    assert!(nrows_a == ncols_a,
+    "The A matrix must have the same number of rows and columns.
+    The number of columns is {} and number of rows is {}",
+    ncols_a, nrows_a);
+
+    assert!(ndim_b == nrows_a,
+    "The number of columns of A must be equal to the number of rows of x vector.
+    The number of rows of x is {} and number of cols of b is {}",
+    ndim_x, ncols_a);
+
+    let tol: F = opt.sol_tol;
+    let m = opt.restart_iter as usize;
+
+    let mut r = Array1::<F>::zeros(ndim_x);
+    a_mat.matvec(x_vec.view(), r.view_mut());
+    Zip::from(&mut r).and(&b_vec).apply(|r, &b| { *r = b - *r; });
+
+    let mut beta: F = r.dot(&r).sqrt();
+    let mut err: F = beta;
+
+    let mut total_iter: u32 = 0;
+    let mut residual_history: Vec<F> = Vec::new();
+
+    while err.abs() >= tol && total_iter < opt.iter_limit {
+
+        let mut v = Array2::<F>::zeros((ndim_x, m + 1));
+        let mut h = Array2::<F>::zeros((m + 1, m));
+        let mut cs = Array1::<F>::zeros(m);
+        let mut sn = Array1::<F>::zeros(m);
+        let mut g = Array1::<F>::zeros(m + 1);
+
+        v.column_mut(0).assign(&r.mapv(|ri| ri / beta));
+        g[0] = beta;
+
+        let mut j_used = 0;
+
+        for j in 0..m {
+
+            if total_iter >= opt.iter_limit{
+                break;
+            }
+
+            total_iter += 1;
+
+            let mut w = Array1::<F>::zeros(ndim_x);
+            a_mat.matvec(v.column(j), w.view_mut());
+
+            //Modified Gram-Schmidt to build the orthonormal Krylov basis and the Hessenberg column
+            for i in 0..=j{
+                let vi = v.column(i);
+                let hij = vi.dot(&w);
+                h[[i, j]] = hij;
+                w.scaled_add(-hij, &vi);
+            }
+
+            let hj1j: F = w.dot(&w).sqrt();
+            let breakdown = hj1j <= F::epsilon();
+
+            //Apply the previously stored Givens rotations to the new column of H
+            for i in 0..j{
+                let temp = cs[i] * h[[i, j]] + sn[i] * h[[i + 1, j]];
+                h[[i + 1, j]] = -sn[i] * h[[i, j]] + cs[i] * h[[i + 1, j]];
+                h[[i, j]] = temp;
+            }
+
+            //Compute and apply the new Givens rotation that zeroes out h_{j+1,j}
+            let denom: F = (h[[j, j]] * h[[j, j]] + hj1j * hj1j).sqrt();
+
+            if denom > F::zero(){
+                cs[j] = h[[j, j]] / denom;
+                sn[j] = hj1j / denom;
+            } else {
+                cs[j] = F::one();
+                sn[j] = F::zero();
+            }
+
+            h[[j, j]] = cs[j] * h[[j, j]] + sn[j] * hj1j;
+            h[[j + 1, j]] = F::zero();
+
+            let g_j = g[j];
+            g[j] = cs[j] * g_j;
+            g[j + 1] = -sn[j] * g_j;
+
+            err = g[j + 1].abs();
+
+            if opt.record_history{
+                residual_history.push(err);
+            }
+
+            j_used = j + 1;
+
+            if !breakdown{
+                v.column_mut(j + 1).assign(&w.mapv(|wi| wi / hj1j));
+            }
+
+            if err.abs() < tol || breakdown{
+                break;
+            }
+        }
+
+        //Back-substitute the upper-triangular system R*y = g[0..j_used] built from the Givens-rotated H
+        let mut y = Array1::<F>::zeros(j_used);
+
+        for i in (0..j_used).rev(){
+            let mut sum = g[i];
+
+            for k in (i + 1)..j_used{
+                sum = sum - h[[i, k]] * y[k];
+            }
+
+            y[i] = sum / h[[i, i]];
+        }
+
+        for i in 0..j_used{
+            x_vec.scaled_add(y[i], &v.column(i));
+        }
+
+        if j_used == 0{
+            break;
+        }
+
+        a_mat.matvec(x_vec.view(), r.view_mut());
+        Zip::from(&mut r).and(&b_vec).apply(|r, &b| { *r = b - *r; });
+        beta = r.dot(&r).sqrt();
+        err = beta;
+    }
+
+    let converged = err.abs() < tol;
+
+    SolverResult { converged, iterations: total_iter, final_residual: err, residual_history }
+}
+
+///A stabilized biconjugate gradient (BiCGSTAB) solver used to iteratively solve a general
+///(possibly nonsymmetric) A x = b type problem. Unlike CGNR/CGNE this works directly on A
+///without forming the normal equations AᵀA, so it avoids squaring A's condition number.
+///Input: a_mat - a `LinearOperator` with nxn dimensions (a dense `ArrayView2` or a sparse `CscMatrix` both work).
+///x_vec - a 1D vector that has n dimensions. The initial values inputted into this vector are the initial guesses to the solution.
+///b_vec - a 1D vector that has n dimensions. The vector RHS of the Ax=b problem.
+///opt - the iterative option structure. It tells us a number of things that we need to worry about for our iterative problems.
+///Output - a `SolverResult` recording whether the solve converged, how many iterations it took,
+///         the final residual norm, and (if `opt.record_history` is set) the residual at every step.
+pub fn bicgstab_solver<F: 'static, A: LinearOperator<F>>(a_mat: A, mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> SolverResult<F>
+    where F: Float + Zero + One
+{
+    //Here we need to assert that all of the dimensions are the correct length or else we need to kill the function
+    let ndim_x = x_vec.len_of(Axis(0));
+
+    let ndim_b = b_vec.len_of(Axis(0));
+
+    let nrows_a = a_mat.nrows();
+    let ncols_a = a_mat.ncols();
+
+    assert!(ndim_b == ndim_x,
+    "The dimensions of the x vector and b vector are not equal to one another.
+    The dimension of x is {} and dimension of b is {}",
+    ndim_x, ndim_b);
+
+    assert!(nrows_a == ncols_a,
+    "The A matrix must have the same number of rows and columns.
+    The number of columns is {} and number of rows is {}",
+    ncols_a, nrows_a);
+
+    assert!(ndim_b == nrows_a,
+    "The number of columns of A must be equal to the number of rows of x vector.
+    The number of rows of x is {} and number of cols of b is {}",
+    ndim_x, ncols_a);
+
+    let tol: F = opt.sol_tol;
+
+    let mut ri = Array1::<F>::zeros(ndim_x);
+    a_mat.matvec(x_vec.view(), ri.view_mut());
+    Zip::from(&mut ri).and(&b_vec).apply(|ri, &b| { *ri = b - *ri; });
+
+    //The shadow residual r_hat0 is fixed for the life of the iteration, except when a breakdown
+    //guard below forces us to restart it from the current residual.
+    let mut r_hat0 = ri.clone();
+
+    let mut pki = Array1::<F>::zeros(ndim_x);
+    let mut vi = Array1::<F>::zeros(ndim_x);
+
+    let mut rho: F = F::one();
+    let mut alpha: F = F::one();
+    let mut omega: F = F::one();
+
+    let mut err: F = ri.dot(&ri).sqrt();
+
+    let mut iterations: u32 = 0;
+    let mut converged = false;
+    let mut residual_history: Vec<F> = Vec::new();
+
+    for _istep in 0..opt.iter_limit{
+
+        if err.abs() < tol{
+            converged = true;
+            break;
+        }
+
+        iterations += 1;
+
+        let mut rho_new = r_hat0.dot(&ri);
+
+        if rho_new.abs() < F::epsilon() || omega.abs() < F::epsilon(){
+            //Breakdown guard: restart the shadow residual and search direction from the current residual.
+            //`alpha`/`omega` are deliberately left alone here: both are unconditionally
+            //recomputed below before they're read again this iteration.
+            r_hat0.assign(&ri);
+            pki.assign(&ri);
+            rho_new = ri.dot(&ri);
+        } else {
+            let beta = (rho_new/rho) * (alpha/omega);
+
+            Zip::from(&mut pki).and(&ri).and(&vi).apply(|pki, &ri, &vi|{
+                *pki = ri + beta * (*pki - omega * vi);
+            });
+        }
+
+        a_mat.matvec(pki.view(), vi.view_mut());
+
+        alpha = rho_new / r_hat0.dot(&vi);
+
+        let mut s = ri.clone();
+        s.scaled_add(-alpha, &vi);
+
+        let s_norm: F = s.dot(&s).sqrt();
+
+        if s_norm.abs() < tol{
+            x_vec.scaled_add(alpha, &pki);
+            err = s_norm;
+            converged = true;
+
+            if opt.record_history{
+                residual_history.push(err);
+            }
+
+            break;
+        }
+
+        let mut t = Array1::<F>::zeros(ndim_x);
+        a_mat.matvec(s.view(), t.view_mut());
+        let tt: F = t.dot(&t);
+
+        omega = if tt.abs() > F::epsilon(){ t.dot(&s) / tt } else { F::zero() };
+
+        x_vec.scaled_add(alpha, &pki);
+        x_vec.scaled_add(omega, &s);
+
+        ri.assign(&s);
+        ri.scaled_add(-omega, &t);
+
+        err = ri.dot(&ri).sqrt();
+
+        if opt.record_history{
+            residual_history.push(err);
+        }
+
+        rho = rho_new;
+    }
+
+    if err.abs() < tol{
+        converged = true;
+    }
+
+    SolverResult { converged, iterations, final_residual: err, residual_history }
+}
+
+///A conjugate gradient least squares (CGLS) solver used to iteratively minimize ‖Ax − b‖₂ for a
+///rectangular (or square) A, i.e. it solves the normal equations AᵀAx = Aᵀb without ever forming
+///AᵀA. Unlike `cgnr_solver`/`cgne_solver`, A need not be square: this is the least-squares
+///solver that the comments above them point to.
+///Input: a_mat - a `LinearOperator` with m rows and n columns (a dense `ArrayView2` or a sparse `CscMatrix` both work).
+///x_vec - a 1D vector that has n dimensions. The initial values inputted into this vector are the initial guesses to the solution.
+///b_vec - a 1D vector that has m dimensions. The vector RHS of the Ax=b problem.
+///opt - the iterative option structure. It tells us a number of things that we need to worry about for our iterative problems.
+///Output - a `SolverResult` recording whether the solve converged, how many iterations it took,
+///         the final normal-equation residual ‖Aᵀr‖₂ (which need not reach zero for an
+///         overdetermined system), and (if `opt.record_history` is set) that residual at every step.
+pub fn cgls_solver<F: 'static, A: LinearOperator<F>>(a_mat: A, mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> SolverResult<F>
+    where F: Float + Zero + One
+{
+    //Here we need to assert that all of the dimensions are the correct length or else we need to kill the function
+    let ndim_x = x_vec.len_of(Axis(0));
+    let ndim_b = b_vec.len_of(Axis(0));
+
+    let nrows_a = a_mat.nrows();
+    let ncols_a = a_mat.ncols();
+
+    assert!(ndim_x == ncols_a,
+    "The number of columns of A must be equal to the number of dimensions of x vector.
+    The number of columns of A is {} and dimension of x is {}",
+    ncols_a, ndim_x);
+
+    assert!(ndim_b == nrows_a,
+    "The number of rows of A must be equal to the number of dimensions of b vector.
+    The number of rows of A is {} and dimension of b is {}",
+    nrows_a, ndim_b);
+
+    let tol: F = opt.sol_tol;
+
+    let mut ri = Array1::<F>::zeros(ndim_b);
+    let mut si = Array1::<F>::zeros(ndim_x);
+    let mut pki = Array1::<F>::zeros(ndim_x);
+    let mut qi = Array1::<F>::zeros(ndim_b);
+
+    let mut alpha: F;
+    let mut beta: F;
+    let mut qtq: F;
+
+    a_mat.matvec(x_vec.view(), ri.view_mut());
+    Zip::from(&mut ri).and(&b_vec).apply(|ri, &b| { *ri = b - *ri; });
+
+    a_mat.matvec_transpose(ri.view(), si.view_mut());
+
+    pki.assign(&si);
+
+    let mut gamma: F = si.dot(&si);
+    let mut err: F = gamma.sqrt();
+
+    let mut iterations: u32 = 0;
+    let mut converged = false;
+    let mut residual_history: Vec<F> = Vec::new();
+
+    for _istep in 0..opt.iter_limit{
+
+        if err.abs() < tol{
+            converged = true;
+            break;
+        }
+
+        iterations += 1;
+
+        a_mat.matvec(pki.view(), qi.view_mut());
+
+        qtq = qi.dot(&qi);
+
+        alpha = gamma/qtq;
+
+        x_vec.scaled_add(alpha, &pki);
+
+        ri.scaled_add(-alpha, &qi);
+
+        a_mat.matvec_transpose(ri.view(), si.view_mut());
+
+        let gamma_new: F = si.dot(&si);
+
+        err = gamma_new.sqrt();
+
+        if opt.record_history{
+            residual_history.push(err);
+        }
+
+        beta = gamma_new/gamma;
+
+        gamma = gamma_new;
+
+        Zip::from(&mut pki).and(&si).apply(|pki, &si|{
+            *pki = si + beta * *pki;
+        });
+
+    }
+
+    if err.abs() < tol{
+        converged = true;
+    }
+
+    SolverResult { converged, iterations, final_residual: err, residual_history }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn approx_eq(a: f64, b: f64, tol: f64) -> bool {
+        (a - b).abs() < tol
+    }
+
+    //The crate-wide f64 default (`1.0e-16`) sits right at the edge of what floating-point
+    //round-off lets these iterative methods actually certify, so tests use a looser tolerance
+    //that's still tight enough to confirm the solvers found the right answer.
+    fn test_opts() -> IterOptions<f64> {
+        IterOptions { sol_tol: 1e-10, ..IterOptions::default() }
+    }
+
+    #[test]
+    fn cg_solver_matches_known_spd_solution() {
+        let a = array![[4.0, 1.0], [1.0, 3.0]];
+        let b = array![1.0, 2.0];
+        let mut x = Array1::<f64>::zeros(2);
+        let opt = test_opts();
+
+        let result = cg_solver(a.view(), x.view_mut(), b.view(), &opt);
+
+        assert!(result.converged);
+        assert!(approx_eq(x[0], 1.0 / 11.0, 1e-8));
+        assert!(approx_eq(x[1], 7.0 / 11.0, 1e-8));
+    }
+
+    #[test]
+    fn gmres_solver_solves_nonsymmetric_system() {
+        let a = array![[2.0, 1.0], [3.0, 4.0]];
+        let b = array![1.0, 1.0];
+        let mut x = Array1::<f64>::zeros(2);
+        let opt = test_opts();
+
+        let result = gmres_solver(a.view(), x.view_mut(), b.view(), &opt);
+
+        assert!(result.converged);
+        assert!(approx_eq(x[0], 0.6, 1e-6));
+        assert!(approx_eq(x[1], -0.2, 1e-6));
+    }
+
+    #[test]
+    fn gmres_solver_converges_with_forced_restarts() {
+        //A tridiagonal, diagonally dominant, nonsymmetric 6x6 system. `restart_iter` is set well
+        //below n so the solver has to restart several times before converging.
+        let n = 6;
+        let mut a = Array2::<f64>::zeros((n, n));
+
+        for i in 0..n {
+            a[[i, i]] = 4.0;
+
+            if i + 1 < n {
+                a[[i, i + 1]] = 1.0;
+                a[[i + 1, i]] = -1.0;
+            }
+        }
+
+        let x_true = array![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut b = Array1::<f64>::zeros(n);
+        a.view().matvec(x_true.view(), b.view_mut());
+
+        let mut x = Array1::<f64>::zeros(n);
+        let opt = IterOptions { restart_iter: 2, ..test_opts() };
+
+        let result = gmres_solver(a.view(), x.view_mut(), b.view(), &opt);
+
+        assert!(result.converged);
+
+        for i in 0..n {
+            assert!(approx_eq(x[i], x_true[i], 1e-6));
+        }
+    }
+
+    #[test]
+    fn bicgstab_solver_solves_nonsymmetric_system() {
+        let a = array![[2.0, 1.0], [3.0, 4.0]];
+        let b = array![1.0, 1.0];
+        let mut x = Array1::<f64>::zeros(2);
+        let opt = test_opts();
+
+        let result = bicgstab_solver(a.view(), x.view_mut(), b.view(), &opt);
+
+        assert!(result.converged);
+        assert!(approx_eq(x[0], 0.6, 1e-6));
+        assert!(approx_eq(x[1], -0.2, 1e-6));
+    }
+
+    #[test]
+    fn cgls_solver_solves_rectangular_least_squares() {
+        //A 3x2 overdetermined but consistent system, so the least-squares solution is exact.
+        let a = array![[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+        let x_true = array![2.0, 3.0];
+        let mut b = Array1::<f64>::zeros(3);
+        a.view().matvec(x_true.view(), b.view_mut());
+
+        let mut x = Array1::<f64>::zeros(2);
+        let opt = test_opts();
+
+        let result = cgls_solver(a.view(), x.view_mut(), b.view(), &opt);
+
+        assert!(result.converged);
+        assert!(approx_eq(x[0], 2.0, 1e-6));
+        assert!(approx_eq(x[1], 3.0, 1e-6));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,146 @@
+use ndarray::prelude::*;
+use libnum::Float;
+
+///An abstraction over "apply A to a vector" so that the solvers in this crate can work with
+///dense matrices, sparse matrices, or purely implicit operators (e.g. a stencil) without
+///forcing the caller to materialize an n×n dense array.
+pub trait LinearOperator<F> {
+    ///The number of rows of the operator.
+    fn nrows(&self) -> usize;
+    ///The number of columns of the operator.
+    fn ncols(&self) -> usize;
+    ///Computes y = A*x.
+    fn matvec(&self, x: ArrayView1<F>, y: ArrayViewMut1<F>);
+    ///Computes y = Aᵀ*x.
+    fn matvec_transpose(&self, x: ArrayView1<F>, y: ArrayViewMut1<F>);
+    ///Writes the diagonal of A (a_ii for i in 0..nrows.min(ncols)) into `d`. This is enough to
+    ///build a Jacobi preconditioner for any operator, sparse or dense, without forcing it to be
+    ///materialized as a dense matrix first.
+    fn diag(&self, d: ArrayViewMut1<F>);
+}
+
+///A blanket impl over dense matrices so that existing call sites that pass an `ArrayView2<F>`
+///keep working unchanged.
+impl<F: Float + 'static> LinearOperator<F> for ArrayView2<'_, F> {
+    fn nrows(&self) -> usize {
+        self.len_of(Axis(0))
+    }
+
+    fn ncols(&self) -> usize {
+        self.len_of(Axis(1))
+    }
+
+    fn matvec(&self, x: ArrayView1<F>, mut y: ArrayViewMut1<F>) {
+        y.assign(&self.dot(&x));
+    }
+
+    fn matvec_transpose(&self, x: ArrayView1<F>, mut y: ArrayViewMut1<F>) {
+        y.assign(&self.t().dot(&x));
+    }
+
+    fn diag(&self, mut d: ArrayViewMut1<F>) {
+        for i in 0..d.len_of(Axis(0)) {
+            d[i] = self[[i, i]];
+        }
+    }
+}
+
+///A sparse matrix stored in compressed sparse column (CSC) format. Column `j`'s nonzero
+///entries live at `row_idx[col_ptr[j]..col_ptr[j+1]]` / `values[col_ptr[j]..col_ptr[j+1]]`.
+///This lets large sparse systems be solved with O(nnz) memory instead of the O(n²) a dense
+///`ArrayView2` would require.
+pub struct CscMatrix<F> {
+    nrows: usize,
+    ncols: usize,
+    col_ptr: Vec<usize>,
+    row_idx: Vec<usize>,
+    values: Vec<F>,
+}
+
+impl<F: Float> CscMatrix<F> {
+    ///Builds a CSC matrix from its raw column-pointer/row-index/value arrays.
+    ///`col_ptr` must have `ncols + 1` entries, and `row_idx`/`values` must be the same length.
+    pub fn new(nrows: usize, ncols: usize, col_ptr: Vec<usize>, row_idx: Vec<usize>, values: Vec<F>) -> CscMatrix<F> {
+        assert!(col_ptr.len() == ncols + 1,
+        "col_ptr must have ncols + 1 entries. The number of columns is {} and col_ptr has {} entries",
+        ncols, col_ptr.len());
+
+        assert!(row_idx.len() == values.len(),
+        "row_idx and values must have the same length. row_idx has {} entries and values has {} entries",
+        row_idx.len(), values.len());
+
+        CscMatrix { nrows, ncols, col_ptr, row_idx, values }
+    }
+}
+
+impl<F: Float> LinearOperator<F> for CscMatrix<F> {
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    fn matvec(&self, x: ArrayView1<F>, mut y: ArrayViewMut1<F>) {
+        y.fill(F::zero());
+
+        for j in 0..self.ncols {
+            let xj = x[j];
+
+            for k in self.col_ptr[j]..self.col_ptr[j + 1] {
+                let i = self.row_idx[k];
+                y[i] = y[i] + self.values[k] * xj;
+            }
+        }
+    }
+
+    fn matvec_transpose(&self, x: ArrayView1<F>, mut y: ArrayViewMut1<F>) {
+        for j in 0..self.ncols {
+            let mut sum = F::zero();
+
+            for k in self.col_ptr[j]..self.col_ptr[j + 1] {
+                sum = sum + self.values[k] * x[self.row_idx[k]];
+            }
+
+            y[j] = sum;
+        }
+    }
+
+    fn diag(&self, mut d: ArrayViewMut1<F>) {
+        for j in 0..d.len_of(Axis(0)) {
+            d[j] = F::zero();
+
+            for k in self.col_ptr[j]..self.col_ptr[j + 1] {
+                if self.row_idx[k] == j {
+                    d[j] = self.values[k];
+                    break;
+                }
+            }
+        }
+    }
+}
+
+///Lets callers pass `&A` (e.g. `&csc_matrix`) wherever an owned `LinearOperator` is expected,
+///since most operators (like `CscMatrix`) are more naturally held by reference.
+impl<F, T: LinearOperator<F> + ?Sized> LinearOperator<F> for &T {
+    fn nrows(&self) -> usize {
+        (**self).nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        (**self).ncols()
+    }
+
+    fn matvec(&self, x: ArrayView1<F>, y: ArrayViewMut1<F>) {
+        (**self).matvec(x, y)
+    }
+
+    fn matvec_transpose(&self, x: ArrayView1<F>, y: ArrayViewMut1<F>) {
+        (**self).matvec_transpose(x, y)
+    }
+
+    fn diag(&self, d: ArrayViewMut1<F>) {
+        (**self).diag(d)
+    }
+}
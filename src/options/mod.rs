@@ -13,6 +13,10 @@ pub struct IterOptions<F: Float>{
     ///This parameter is used for methods such as GMRES methods where we restart our
     ///search for the correct solution.
     pub restart_iter: u32,
+    ///Whether the solvers should populate `SolverResult::residual_history` with the residual
+    ///norm from every step. This costs one `Vec` push per iteration, so hot paths that call a
+    ///solver repeatedly and only care about the final residual should leave this `false`.
+    pub record_history: bool,
 
 }
 
@@ -23,6 +27,7 @@ impl Default for IterOptions<f32>{
             sol_tol: 1.0e-7,
             iter_limit: 10000,
             restart_iter: 25,
+            record_history: false,
         }
     }
 }
@@ -34,6 +39,7 @@ impl Default for IterOptions<f64>{
             sol_tol: 1.0e-16,
             iter_limit: 10000,
             restart_iter: 25,
+            record_history: false,
         }
     }
 }
\ No newline at end of file
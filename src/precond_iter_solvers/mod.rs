@@ -1,55 +1,169 @@
 use ndarray::prelude::*;
+use ndarray::Zip;
 use libnum::{Zero, One, Float};
 
 use super::options::*;
+use super::operator::LinearOperator;
+use super::result::SolverResult;
 
+///A preconditioner for the preconditioned conjugate gradient method.
+///Implementors provide an approximate inverse application z = M⁻¹r that is cheap to
+///compute relative to solving Ax = b directly. The better M approximates A, the faster
+///`pcg_solver` converges.
+pub trait Preconditioner<F> {
+    ///Applies M⁻¹ to `r`, writing the result into `z`.
+    fn apply(&self, r: ArrayView1<F>, z: ArrayViewMut1<F>);
+}
+
+///The trivial preconditioner M = I. Using this reduces `pcg_solver` to plain conjugate
+///gradient, which is useful both as a sanity check and as the default when no better
+///preconditioner is available.
+pub struct IdentityPreconditioner;
+
+impl<F: Float> Preconditioner<F> for IdentityPreconditioner {
+    fn apply(&self, r: ArrayView1<F>, mut z: ArrayViewMut1<F>) {
+        z.assign(&r);
+    }
+}
+
+///A Jacobi (diagonal) preconditioner, M = diag(A). This is the cheapest nontrivial
+///preconditioner to build and apply, and is a solid default for diagonally dominant
+///systems.
+pub struct JacobiPreconditioner<F> {
+    inv_diag: Array1<F>,
+}
 
-pub fn pcg_solver<F>(a_mat: ArrayView2<F>, p_mat: ArrayView2<F>, x_vec: ArrayView1<F>, b_vec: ArrayViewMut1<F>, opt: &IterOptions<F>) -> F 
+impl<F: Float> JacobiPreconditioner<F> {
+    ///Builds the preconditioner from the diagonal of `a_mat`, storing 1/a_ii for each row.
+    ///`a_mat` can be any `LinearOperator` (a dense `ArrayView2`, a sparse `CscMatrix`, or an
+    ///implicit operator that implements `diag`), so this works for the sparse/matrix-free A
+    ///that `pcg_solver` itself accepts.
+    pub fn new<A: LinearOperator<F>>(a_mat: &A) -> JacobiPreconditioner<F> {
+        let ndim = a_mat.ncols();
+
+        let mut inv_diag = Array1::<F>::zeros(ndim);
+
+        a_mat.diag(inv_diag.view_mut());
+
+        for i in 0..ndim {
+            inv_diag[i] = F::one() / inv_diag[i];
+        }
+
+        JacobiPreconditioner { inv_diag }
+    }
+}
+
+impl<F: Float> Preconditioner<F> for JacobiPreconditioner<F> {
+    fn apply(&self, r: ArrayView1<F>, mut z: ArrayViewMut1<F>) {
+        Zip::from(&mut z).and(&r).and(&self.inv_diag).apply(|z, &r, &inv_diag| {
+            *z = inv_diag * r;
+        });
+    }
+}
+
+///A preconditioned conjugate gradient (PCG) solver used to iteratively solve a symmetric
+///A x = b type problem.
+///Input: a_mat - a `LinearOperator` with nxn dimensions (a dense `ArrayView2` or a sparse `CscMatrix` both work).
+///This matrix must also be symmetric in order to use the conjugate gradient method.
+///precond - a preconditioner supplying an approximate M⁻¹ application. Pass an `IdentityPreconditioner` to recover plain CG.
+///x_vec - a 1D vector that has n dimensions. The initial values inputted into this vector are the initial guesses to the solution.
+///b_vec - a 1D vector that has n dimensions. The vector RHS of the Ax=b problem.
+///opt - the iterative option structure. It tells us a number of things that we need to worry about for our iterative problems.
+///Output - a `SolverResult` recording whether the solve converged, how many iterations it took,
+///         the final residual norm, and (if `opt.record_history` is set) the residual at every step.
+pub fn pcg_solver<F: 'static, A: LinearOperator<F>>(a_mat: A, precond: &dyn Preconditioner<F>, mut x_vec: ArrayViewMut1<F>, b_vec: ArrayView1<F>, opt: &IterOptions<F>) -> SolverResult<F>
     where F: Float + Zero + One
-{   
+{
     //Here we need to assert that all of the dimensions are the correct length or else we need to kill the function
     let ndim_x = x_vec.len_of(Axis(0));
     let ndim_b = b_vec.len_of(Axis(0));
 
-    let nrows_a = a_mat.len_of(Axis(0));
-    let nrows_p = p_mat.len_of(Axis(0));
+    let nrows_a = a_mat.nrows();
+    let ncols_a = a_mat.ncols();
 
-    let ncols_a = a_mat.len_of(Axis(1));
-    let ncols_p = p_mat.len_of(Axis(1));
-
-    assert!(ndim_b == ndim_x, 
+    assert!(ndim_b == ndim_x,
     "The dimensions of the x vector and b vector are not equal to one another.
     The dimension of x is {} and dimension of b is {}",
     ndim_x, ndim_b);
 
-    assert!(nrows_a == ncols_a, 
+    assert!(nrows_a == ncols_a,
     "The A matrix must have the same number of rows and columns.
     The number of columns is {} and number of rows is {}",
     ncols_a, nrows_a);
 
-    assert!(nrows_p == ncols_p,
-    "The preconditioned matrix must have the same number of rows and columns.
-    The number of columns is {} and number of rows is {}",
-    ncols_p, nrows_p);
-
-    assert!((ncols_p == ncols_a) & (nrows_p == nrows_a),
-    "The preconditioned and A matrix must have the same number of rows and columns.
-    The number of columns is {} and number of rows is {} in the preconditioned matrix.
-    The number of columns is {} and number of rows is {} in the A matrix.",
-    ncols_p, nrows_p, ncols_a, nrows_a);
-
-    assert!(ndim_b == nrows_a, 
+    assert!(ndim_b == nrows_a,
     "The number of columns of A must be equal to the number of rows of x vector.
     The number of rows of x is {} and number of cols of b is {}",
     ndim_x, ncols_a);
 
-    let err: F = F::one();
-    let _tol: F = opt.sol_tol;
+    let tol: F = opt.sol_tol;
 
-    err
+    let mut ri = Array1::<F>::zeros(ndim_x);
+    let mut zi = Array1::<F>::zeros(ndim_x);
+    let mut pki = Array1::<F>::zeros(ndim_x);
+    let mut a_pk = Array1::<F>::zeros(ndim_x);
 
-    // for iter in 0 .. opt.iter_limit{
+    let mut alpha: F;
+    let mut beta: F;
+    let mut pkt_a_pk: F;
 
-    // }
-}
+    a_mat.matvec(x_vec.view(), ri.view_mut());
+    Zip::from(&mut ri).and(&b_vec).apply(|ri, &b| { *ri = b - *ri; });
+
+    precond.apply(ri.view(), zi.view_mut());
+
+    pki.assign(&zi);
 
+    let mut rtz: F = ri.dot(&zi);
+    let mut err: F = ri.dot(&ri).sqrt();
+
+    let mut residual_history: Vec<F> = Vec::new();
+
+    if err.abs() < tol {
+        return SolverResult { converged: true, iterations: 0, final_residual: err, residual_history };
+    }
+
+    let mut iterations: u32 = 0;
+    let mut converged = false;
+
+    for _istep in 0..opt.iter_limit {
+
+        iterations += 1;
+
+        a_mat.matvec(pki.view(), a_pk.view_mut());
+
+        pkt_a_pk = pki.dot(&a_pk);
+
+        alpha = rtz / pkt_a_pk;
+
+        x_vec.scaled_add(alpha, &pki);
+
+        ri.scaled_add(-alpha, &a_pk);
+
+        err = ri.dot(&ri).sqrt();
+
+        if opt.record_history {
+            residual_history.push(err);
+        }
+
+        if err.abs() < tol {
+            converged = true;
+            break;
+        }
+
+        precond.apply(ri.view(), zi.view_mut());
+
+        let rtz_new: F = ri.dot(&zi);
+
+        beta = rtz_new / rtz;
+
+        rtz = rtz_new;
+
+        Zip::from(&mut pki).and(&zi).apply(|pki, &zi| {
+            *pki = zi + beta * *pki;
+        });
+
+    }
+
+    SolverResult { converged, iterations, final_residual: err, residual_history }
+}
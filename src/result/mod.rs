@@ -0,0 +1,16 @@
+///The outcome of running one of this crate's iterative solvers, in place of a bare residual
+///`F`. Callers can check `converged` to know whether `iter_limit` was hit before the tolerance
+///test passed, inspect `iterations` and `residual_history` to detect stagnation, or plot the
+///convergence curve.
+pub struct SolverResult<F> {
+    ///Whether the residual dropped below `IterOptions::sol_tol` before `iter_limit` was reached.
+    pub converged: bool,
+    ///The number of iterations actually taken.
+    pub iterations: u32,
+    ///The residual norm (`‖r‖₂`) at the final iteration. For `cgls_solver` this is instead the
+    ///normal-equation residual `‖Aᵀr‖₂`, which is also what its `residual_history` holds.
+    pub final_residual: F,
+    ///The residual norm after every step, in order. Only populated when
+    ///`IterOptions::record_history` is `true`; empty otherwise.
+    pub residual_history: Vec<F>,
+}